@@ -0,0 +1,38 @@
+use NodeId;
+
+///
+/// Describes where in the `Tree` a new `Node` should be inserted.
+///
+pub enum InsertBehavior<'a> {
+    ///
+    /// The new `Node` becomes the root of the `Tree`.  If the `Tree` already has a root, the
+    /// existing root is added as a child of the new one.
+    ///
+    AsRoot,
+    ///
+    /// The new `Node` is appended to the children of the `Node` identified by the given `NodeId`.
+    ///
+    UnderNode(&'a NodeId),
+}
+
+///
+/// Describes what should happen to a removed `Node`'s children.
+///
+pub enum RemoveBehavior {
+    ///
+    /// The removed `Node`'s children are removed along with it.
+    ///
+    DropChildren,
+    ///
+    /// The removed `Node`'s children take its place under its former parent.  If it was the root,
+    /// its first child becomes the new root and any remaining children are attached under that new
+    /// root, mirroring how `InsertBehavior::AsRoot` hangs the old root off the new one — a `Tree`
+    /// only ever tracks a single root, so there is nowhere else for them to go.  If it had no
+    /// parent and was not the root, its children become parentless.
+    ///
+    LiftChildren,
+    ///
+    /// The removed `Node`'s children are kept in the `Tree`, but become parentless.
+    ///
+    OrphanChildren,
+}
@@ -0,0 +1,33 @@
+use std::error::Error;
+use std::fmt;
+
+///
+/// An `Error` type for failed operations involving `NodeId`s.
+///
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum NodeIdError {
+    ///
+    /// Returned when a `NodeId` doesn't match a live slot in the `Tree` it is used with, either
+    /// because it was never produced by that `Tree` or because the `Node` it pointed to has since
+    /// been removed.
+    ///
+    NodeIdNoLongerValid,
+}
+
+impl fmt::Display for NodeIdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NodeIdError::NodeIdNoLongerValid => {
+                write!(f, "The NodeId provided is no longer valid.")
+            }
+        }
+    }
+}
+
+impl Error for NodeIdError {
+    fn description(&self) -> &str {
+        match *self {
+            NodeIdError::NodeIdNoLongerValid => "The NodeId provided is no longer valid.",
+        }
+    }
+}
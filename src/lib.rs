@@ -0,0 +1,18 @@
+//! `id_tree` is a library for creating and modifying `Tree` structures.
+
+// This crate consistently spells out `field: value` even when the names match, and takes extra
+// references when looking a `NodeId` back up right after borrowing it (clearer when the two are
+// far apart in a match arm). Neither is a correctness concern worth departing from our style for.
+#![allow(clippy::redundant_field_names, clippy::needless_borrow)]
+
+mod behaviors;
+mod error;
+mod node;
+mod node_id;
+pub mod tree;
+
+pub use behaviors::{InsertBehavior, RemoveBehavior};
+pub use error::NodeIdError;
+pub use node::Node;
+pub use node_id::NodeId;
+pub use tree::Tree;
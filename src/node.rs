@@ -0,0 +1,64 @@
+use NodeId;
+
+///
+/// A single element in a `Tree`, wrapping the caller's data along with links to its parent and
+/// children.
+///
+/// A `Node` is created on its own with `Node::new` and then handed to `Tree::insert`, which takes
+/// ownership of it and wires up its parent and children links in the `Tree` itself.
+///
+#[derive(Debug, PartialEq, Clone)]
+pub struct Node<T> {
+    data: T,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+impl<T> Node<T> {
+    ///
+    /// Creates a new `Node` wrapping `data`, with no parent and no children.
+    ///
+    pub fn new(data: T) -> Node<T> {
+        Node {
+            data: data,
+            parent: None,
+            children: Vec::new(),
+        }
+    }
+
+    ///
+    /// Returns an immutable reference to this `Node`'s data.
+    ///
+    pub fn data(&self) -> &T {
+        &self.data
+    }
+
+    ///
+    /// Returns a mutable reference to this `Node`'s data.
+    ///
+    pub fn data_mut(&mut self) -> &mut T {
+        &mut self.data
+    }
+
+    ///
+    /// Returns the `NodeId` of this `Node`'s parent, if it has one.
+    ///
+    pub fn parent(&self) -> Option<&NodeId> {
+        self.parent.as_ref()
+    }
+
+    ///
+    /// Returns the `NodeId`s of this `Node`'s children, in order.
+    ///
+    pub fn children(&self) -> &Vec<NodeId> {
+        &self.children
+    }
+
+    pub(crate) fn set_parent(&mut self, parent: Option<NodeId>) {
+        self.parent = parent;
+    }
+
+    pub(crate) fn children_mut(&mut self) -> &mut Vec<NodeId> {
+        &mut self.children
+    }
+}
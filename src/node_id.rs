@@ -0,0 +1,58 @@
+///
+/// An identifier used to differentiate between `Node`s within a `Tree`.
+///
+/// `NodeId`s are not something that you ever create manually.  A `Tree` will hand them out via
+/// the various methods that insert new `Node`s, and you pass them back in to look `Node`s up or
+/// to move/remove them.
+///
+/// Internally a `NodeId` is a generational index: it pairs an index into the `Tree`'s backing
+/// storage with a generation counter for that slot.  Removing a `Node` bumps the generation of
+/// the slot it occupied and returns the slot to a free-list for reuse, so a `NodeId` obtained
+/// before the removal no longer matches the slot that replaces it.  `Tree::get`/`Tree::get_mut`
+/// compare the `NodeId`'s generation against the slot's current generation and return `None` on a
+/// mismatch, turning what would otherwise be silent aliasing of the wrong `Node` into a clean
+/// `None`.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NodeId {
+    index: usize,
+    generation: u64,
+}
+
+impl NodeId {
+    pub(crate) fn new(index: usize, generation: u64) -> NodeId {
+        NodeId {
+            index: index,
+            generation: generation,
+        }
+    }
+
+    pub(crate) fn index(&self) -> usize {
+        self.index
+    }
+
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NodeId;
+
+    #[test]
+    fn test_same_index_and_generation_are_equal() {
+        let a = NodeId::new(0, 0);
+        let b = NodeId::new(0, 0);
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_stale_generation_is_not_equal() {
+        let a = NodeId::new(0, 0);
+        let b = NodeId::new(0, 1);
+
+        assert_ne!(a, b);
+    }
+}
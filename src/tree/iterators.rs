@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::slice::Iter;
 
 use Tree;
@@ -106,8 +107,23 @@ impl<'a, T> Iterator for Children<'a, T> {
         }
         None
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.child_ids.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Children<'a, T> {
+    fn next_back(&mut self) -> Option<&'a Node<T>> {
+        if let Some(ref next_child_id) = self.child_ids.next_back() {
+            return Some(self.tree.get_unsafe(next_child_id));
+        }
+        None
+    }
 }
 
+impl<'a, T> ExactSizeIterator for Children<'a, T> {}
+
 ///
 /// An Iterator over the children of a `Node`.
 ///
@@ -117,7 +133,7 @@ pub struct ChildrenIds<'a> {
     child_ids: Iter<'a, NodeId>,
 }
 
-impl<'a, T> IteratorNew<'a, T, ChildrenIds<'a>> for ChildrenIds<'a> {
+impl<'a, T: 'a> IteratorNew<'a, T, ChildrenIds<'a>> for ChildrenIds<'a> {
     fn new(tree: &'a Tree<T>, node_id: NodeId) -> ChildrenIds<'a> {
         ChildrenIds { child_ids: tree.get_unsafe(&node_id).children().as_slice().iter() }
     }
@@ -129,6 +145,270 @@ impl<'a> Iterator for ChildrenIds<'a> {
     fn next(&mut self) -> Option<&'a NodeId> {
         self.child_ids.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.child_ids.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for ChildrenIds<'a> {
+    fn next_back(&mut self) -> Option<&'a NodeId> {
+        self.child_ids.next_back()
+    }
+}
+
+impl<'a> ExactSizeIterator for ChildrenIds<'a> {}
+
+///
+/// An Iterator over the sub-tree relative to a given `Node`, in level-order (breadth-first).
+///
+/// Iterates over the given `Node` and its sub-tree in the `Tree`, visiting them level by level
+/// (the starting `Node` is yielded first, then all `Node`s at a given depth are yielded before
+/// any `Node`s at the next depth).  Each call to `next` will return an immutable reference to the
+/// next `Node`.
+///
+pub struct LevelOrderTraversal<'a, T: 'a> {
+    tree: &'a Tree<T>,
+    to_visit: VecDeque<NodeId>,
+}
+
+impl<'a, T> IteratorNew<'a, T, LevelOrderTraversal<'a, T>> for LevelOrderTraversal<'a, T> {
+    fn new(tree: &'a Tree<T>, node_id: NodeId) -> LevelOrderTraversal<'a, T> {
+        let mut to_visit = VecDeque::new();
+        to_visit.push_back(node_id);
+
+        LevelOrderTraversal {
+            tree: tree,
+            to_visit: to_visit,
+        }
+    }
+}
+
+impl<'a, T> Iterator for LevelOrderTraversal<'a, T> {
+    type Item = &'a Node<T>;
+
+    fn next(&mut self) -> Option<&'a Node<T>> {
+        if let Some(node_id) = self.to_visit.pop_front() {
+            let node = self.tree.get_unsafe(&node_id);
+
+            for child_id in node.children() {
+                self.to_visit.push_back(child_id.clone());
+            }
+
+            return Some(node);
+        }
+        None
+    }
+}
+
+///
+/// An Iterator over the sub-tree relative to a given `Node`, in level-order (breadth-first).
+///
+/// Iterates over `NodeId`s instead of over the `Node`s themselves.
+///
+pub struct LevelOrderTraversalIds<'a, T: 'a> {
+    tree: &'a Tree<T>,
+    to_visit: VecDeque<NodeId>,
+}
+
+impl<'a, T> IteratorNew<'a, T, LevelOrderTraversalIds<'a, T>> for LevelOrderTraversalIds<'a, T> {
+    fn new(tree: &'a Tree<T>, node_id: NodeId) -> LevelOrderTraversalIds<'a, T> {
+        let mut to_visit = VecDeque::new();
+        to_visit.push_back(node_id);
+
+        LevelOrderTraversalIds {
+            tree: tree,
+            to_visit: to_visit,
+        }
+    }
+}
+
+impl<'a, T> Iterator for LevelOrderTraversalIds<'a, T> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<NodeId> {
+        if let Some(node_id) = self.to_visit.pop_front() {
+            let node = self.tree.get_unsafe(&node_id);
+
+            for child_id in node.children() {
+                self.to_visit.push_back(child_id.clone());
+            }
+
+            return Some(node_id);
+        }
+        None
+    }
+}
+
+fn following_sibling_ids<'a, T>(tree: &'a Tree<T>, node_id: &NodeId) -> Iter<'a, NodeId> {
+    match tree.get_unsafe(node_id).parent() {
+        Some(parent_id) => {
+            let siblings = tree.get_unsafe(parent_id).children().as_slice();
+            let index = siblings.iter().position(|id| id == node_id).unwrap();
+            siblings[index + 1..].iter()
+        }
+        None => [].iter(),
+    }
+}
+
+fn preceding_sibling_ids<'a, T>(tree: &'a Tree<T>, node_id: &NodeId) -> Iter<'a, NodeId> {
+    match tree.get_unsafe(node_id).parent() {
+        Some(parent_id) => {
+            let siblings = tree.get_unsafe(parent_id).children().as_slice();
+            let index = siblings.iter().position(|id| id == node_id).unwrap();
+            siblings[..index].iter()
+        }
+        None => [].iter(),
+    }
+}
+
+///
+/// An Iterator over the siblings that come after a `Node`.
+///
+/// Iterates over the `Node`s that share a parent with a given `Node` and appear after it in the
+/// parent's list of children, starting with the closest sibling.  Each call to `next` will return
+/// an immutable reference to the next sibling `Node`.  If the `Node` has no parent (i.e. it is the
+/// root), the iterator yields nothing.
+///
+pub struct NextSiblings<'a, T: 'a> {
+    tree: &'a Tree<T>,
+    sibling_ids: Iter<'a, NodeId>,
+}
+
+impl<'a, T> IteratorNew<'a, T, NextSiblings<'a, T>> for NextSiblings<'a, T> {
+    fn new(tree: &'a Tree<T>, node_id: NodeId) -> NextSiblings<'a, T> {
+        NextSiblings {
+            tree: tree,
+            sibling_ids: following_sibling_ids(tree, &node_id),
+        }
+    }
+}
+
+impl<'a, T> Iterator for NextSiblings<'a, T> {
+    type Item = &'a Node<T>;
+
+    fn next(&mut self) -> Option<&'a Node<T>> {
+        if let Some(next_sibling_id) = self.sibling_ids.next() {
+            return Some(self.tree.get_unsafe(next_sibling_id));
+        }
+        None
+    }
+}
+
+///
+/// An Iterator over the siblings that come after a `Node`.
+///
+/// Iterates over `NodeId`s instead of over the `Node`s themselves.
+///
+pub struct NextSiblingsIds<'a> {
+    sibling_ids: Iter<'a, NodeId>,
+}
+
+impl<'a, T: 'a> IteratorNew<'a, T, NextSiblingsIds<'a>> for NextSiblingsIds<'a> {
+    fn new(tree: &'a Tree<T>, node_id: NodeId) -> NextSiblingsIds<'a> {
+        NextSiblingsIds { sibling_ids: following_sibling_ids(tree, &node_id) }
+    }
+}
+
+impl<'a> Iterator for NextSiblingsIds<'a> {
+    type Item = &'a NodeId;
+
+    fn next(&mut self) -> Option<&'a NodeId> {
+        self.sibling_ids.next()
+    }
+}
+
+///
+/// An Iterator over the siblings that come before a `Node`.
+///
+/// Iterates over the `Node`s that share a parent with a given `Node` and appear before it in the
+/// parent's list of children, starting with the closest sibling and walking back towards the
+/// first child.  Each call to `next` will return an immutable reference to the next sibling
+/// `Node`.  If the `Node` has no parent (i.e. it is the root), the iterator yields nothing.
+///
+pub struct PrecedingSiblings<'a, T: 'a> {
+    tree: &'a Tree<T>,
+    sibling_ids: Iter<'a, NodeId>,
+}
+
+impl<'a, T> IteratorNew<'a, T, PrecedingSiblings<'a, T>> for PrecedingSiblings<'a, T> {
+    fn new(tree: &'a Tree<T>, node_id: NodeId) -> PrecedingSiblings<'a, T> {
+        PrecedingSiblings {
+            tree: tree,
+            sibling_ids: preceding_sibling_ids(tree, &node_id),
+        }
+    }
+}
+
+impl<'a, T> Iterator for PrecedingSiblings<'a, T> {
+    type Item = &'a Node<T>;
+
+    fn next(&mut self) -> Option<&'a Node<T>> {
+        if let Some(next_sibling_id) = self.sibling_ids.next_back() {
+            return Some(self.tree.get_unsafe(next_sibling_id));
+        }
+        None
+    }
+}
+
+///
+/// An Iterator over the siblings that come before a `Node`.
+///
+/// Iterates over `NodeId`s instead of over the `Node`s themselves.
+///
+pub struct PrecedingSiblingsIds<'a> {
+    sibling_ids: Iter<'a, NodeId>,
+}
+
+impl<'a, T: 'a> IteratorNew<'a, T, PrecedingSiblingsIds<'a>> for PrecedingSiblingsIds<'a> {
+    fn new(tree: &'a Tree<T>, node_id: NodeId) -> PrecedingSiblingsIds<'a> {
+        PrecedingSiblingsIds { sibling_ids: preceding_sibling_ids(tree, &node_id) }
+    }
+}
+
+impl<'a> Iterator for PrecedingSiblingsIds<'a> {
+    type Item = &'a NodeId;
+
+    fn next(&mut self) -> Option<&'a NodeId> {
+        self.sibling_ids.next_back()
+    }
+}
+
+///
+/// An Iterator over the path from the root of the `Tree` down to a `Node`'s parent.
+///
+/// Yields the same `NodeId`s as `AncestorIds` (so not the `Node`'s own), but in the opposite,
+/// root-first order, which is
+/// what callers reconstructing a breadcrumb path or a canonical path string need.  Because the
+/// first `Node` to yield (the root) is only known once the walk up to it has finished, the full
+/// chain is collected eagerly when the iterator is constructed.
+///
+pub struct PathToRoot<'a> {
+    path: ::std::vec::IntoIter<&'a NodeId>,
+}
+
+impl<'a, T: 'a> IteratorNew<'a, T, PathToRoot<'a>> for PathToRoot<'a> {
+    fn new(tree: &'a Tree<T>, node_id: NodeId) -> PathToRoot<'a> {
+        let mut path = Vec::new();
+        let mut current = tree.get_unsafe(&node_id).parent();
+
+        while let Some(ancestor_id) = current {
+            current = tree.get_unsafe(ancestor_id).parent();
+            path.push(ancestor_id);
+        }
+
+        path.reverse();
+
+        PathToRoot { path: path.into_iter() }
+    }
+}
+
+impl<'a> Iterator for PathToRoot<'a> {
+    type Item = &'a NodeId;
+
+    fn next(&mut self) -> Option<&'a NodeId> {
+        self.path.next()
+    }
 }
 
 #[cfg(test)]
@@ -245,4 +525,148 @@ mod tests {
         let children_ids = tree.children_ids(&node_3).unwrap();
         assert_eq!(children_ids.count(), 0);
     }
+
+    #[test]
+    fn test_children_rev() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new(0), AsRoot).unwrap();
+        tree.insert(Node::new(1), UnderNode(&root_id)).unwrap();
+        tree.insert(Node::new(2), UnderNode(&root_id)).unwrap();
+        tree.insert(Node::new(3), UnderNode(&root_id)).unwrap();
+
+        let mut children = tree.children(&root_id).unwrap();
+        assert_eq!(children.len(), 3);
+        assert_eq!(children.next_back().unwrap().data(), &3);
+        assert_eq!(children.next().unwrap().data(), &1);
+        assert_eq!(children.next_back().unwrap().data(), &2);
+        assert_eq!(children.len(), 0);
+        assert!(children.next().is_none());
+    }
+
+    #[test]
+    fn test_children_ids_rev() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new(0), AsRoot).unwrap();
+        tree.insert(Node::new(1), UnderNode(&root_id)).unwrap();
+        tree.insert(Node::new(2), UnderNode(&root_id)).unwrap();
+        tree.insert(Node::new(3), UnderNode(&root_id)).unwrap();
+
+        let mut children_ids = tree.children_ids(&root_id).unwrap();
+        assert_eq!(children_ids.len(), 3);
+
+        let last_id = children_ids.next_back().unwrap();
+        assert_eq!(tree.get(last_id).unwrap().data(), &3);
+        assert_eq!(children_ids.len(), 2);
+    }
+
+    #[test]
+    fn test_level_order_traversal() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new(0), AsRoot).unwrap();
+        let node_1 = tree.insert(Node::new(1), UnderNode(&root_id)).unwrap();
+        tree.insert(Node::new(2), UnderNode(&node_1)).unwrap();
+        tree.insert(Node::new(3), UnderNode(&node_1)).unwrap();
+
+        let data = [0, 1, 2, 3];
+        for (index, node) in tree.traverse_level_order(&root_id).unwrap().enumerate() {
+            assert_eq!(node.data(), &data[index]);
+        }
+
+        let data = [1, 2, 3];
+        for (index, node) in tree.traverse_level_order(&node_1).unwrap().enumerate() {
+            assert_eq!(node.data(), &data[index]);
+        }
+    }
+
+    #[test]
+    fn test_level_order_traversal_ids() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new(0), AsRoot).unwrap();
+        let node_1 = tree.insert(Node::new(1), UnderNode(&root_id)).unwrap();
+        tree.insert(Node::new(2), UnderNode(&node_1)).unwrap();
+        tree.insert(Node::new(3), UnderNode(&node_1)).unwrap();
+
+        let data = [0, 1, 2, 3];
+        for (index, node_id) in tree.traverse_level_order_ids(&root_id).unwrap().enumerate() {
+            assert_eq!(tree.get(&node_id).unwrap().data(), &data[index]);
+        }
+    }
+
+    #[test]
+    fn test_next_siblings() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new(0), AsRoot).unwrap();
+        let node_1 = tree.insert(Node::new(1), UnderNode(&root_id)).unwrap();
+        let node_2 = tree.insert(Node::new(2), UnderNode(&root_id)).unwrap();
+        let node_3 = tree.insert(Node::new(3), UnderNode(&root_id)).unwrap();
+
+        let data = [2, 3];
+        for (index, node) in tree.next_siblings(&node_1).unwrap().enumerate() {
+            assert_eq!(node.data(), &data[index]);
+        }
+
+        let data = [3];
+        for (index, node) in tree.next_siblings(&node_2).unwrap().enumerate() {
+            assert_eq!(node.data(), &data[index]);
+        }
+
+        let next_siblings = tree.next_siblings(&node_3).unwrap();
+        assert_eq!(next_siblings.count(), 0);
+
+        let next_siblings = tree.next_siblings(&root_id).unwrap();
+        assert_eq!(next_siblings.count(), 0);
+    }
+
+    #[test]
+    fn test_preceding_siblings() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new(0), AsRoot).unwrap();
+        let node_1 = tree.insert(Node::new(1), UnderNode(&root_id)).unwrap();
+        let node_2 = tree.insert(Node::new(2), UnderNode(&root_id)).unwrap();
+        let node_3 = tree.insert(Node::new(3), UnderNode(&root_id)).unwrap();
+
+        let data = [2, 1];
+        for (index, node) in tree.preceding_siblings(&node_3).unwrap().enumerate() {
+            assert_eq!(node.data(), &data[index]);
+        }
+
+        let data = [1];
+        for (index, node) in tree.preceding_siblings(&node_2).unwrap().enumerate() {
+            assert_eq!(node.data(), &data[index]);
+        }
+
+        let preceding_siblings = tree.preceding_siblings(&node_1).unwrap();
+        assert_eq!(preceding_siblings.count(), 0);
+
+        let preceding_siblings = tree.preceding_siblings(&root_id).unwrap();
+        assert_eq!(preceding_siblings.count(), 0);
+    }
+
+    #[test]
+    fn test_path_to_root() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new(0), AsRoot).unwrap();
+        let node_1 = tree.insert(Node::new(1), UnderNode(&root_id)).unwrap();
+        let node_2 = tree.insert(Node::new(2), UnderNode(&node_1)).unwrap();
+
+        let path_to_root = tree.path_to_root(&root_id).unwrap();
+        assert_eq!(path_to_root.count(), 0);
+
+        let data = [0];
+        for (index, node_id) in tree.path_to_root(&node_1).unwrap().enumerate() {
+            assert_eq!(tree.get(node_id).unwrap().data(), &data[index]);
+        }
+
+        let data = [0, 1];
+        for (index, node_id) in tree.path_to_root(&node_2).unwrap().enumerate() {
+            assert_eq!(tree.get(node_id).unwrap().data(), &data[index]);
+        }
+    }
 }
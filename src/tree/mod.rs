@@ -0,0 +1,476 @@
+pub mod iterators;
+mod path;
+
+use InsertBehavior;
+use InsertBehavior::*;
+use Node;
+use NodeId;
+use NodeIdError;
+use RemoveBehavior;
+use RemoveBehavior::*;
+
+use self::iterators::{Ancestors, AncestorIds, Children, ChildrenIds, LevelOrderTraversal,
+                       LevelOrderTraversalIds, NextSiblings, NextSiblingsIds, PrecedingSiblings,
+                       PrecedingSiblingsIds, PathToRoot};
+
+///
+/// Bridges a `Tree`/`NodeId` pair to one of the `Iterator`s in `tree::iterators`.
+///
+/// Every iterator in this crate is built the same way: give it the `Tree` to walk and the
+/// `NodeId` to start from, and it figures out the rest.
+///
+#[allow(clippy::new_ret_no_self)]
+pub trait IteratorNew<'a, T: 'a, I: Iterator> {
+    fn new(tree: &'a Tree<T>, node_id: NodeId) -> I;
+}
+
+struct Slot<T> {
+    generation: u64,
+    value: Option<Node<T>>,
+}
+
+///
+/// A tree data structure.
+///
+/// `Tree`s own their `Node`s in a single backing arena, and hand out `NodeId`s to let callers
+/// refer back to them.  `NodeId`s are generational: once a `Node` is removed, its slot is reused
+/// for a future insertion under a new generation, so any `NodeId` obtained before the removal
+/// stops matching that slot instead of silently resolving to whatever `Node` ends up there next.
+///
+pub struct Tree<T> {
+    root: Option<NodeId>,
+    nodes: Vec<Slot<T>>,
+    free_ids: Vec<usize>,
+}
+
+impl<T> Tree<T> {
+    ///
+    /// Creates a new, empty `Tree`.
+    ///
+    pub fn new() -> Tree<T> {
+        Tree {
+            root: None,
+            nodes: Vec::new(),
+            free_ids: Vec::new(),
+        }
+    }
+
+    ///
+    /// Returns the `NodeId` of the root `Node`, if the `Tree` has one.
+    ///
+    pub fn root_node_id(&self) -> Option<&NodeId> {
+        self.root.as_ref()
+    }
+
+    ///
+    /// Inserts `node` into the `Tree` according to `behavior`, returning the `NodeId` of the
+    /// newly inserted `Node`.
+    ///
+    pub fn insert(&mut self, node: Node<T>, behavior: InsertBehavior) -> Result<NodeId, NodeIdError> {
+        match behavior {
+            AsRoot => {
+                let new_id = self.insert_in_slot(node);
+
+                if let Some(old_root) = self.root.take() {
+                    self.get_mut_unsafe(&old_root).set_parent(Some(new_id.clone()));
+                    self.get_mut_unsafe(&new_id).children_mut().push(old_root);
+                }
+
+                self.root = Some(new_id.clone());
+                Ok(new_id)
+            }
+            UnderNode(parent_id) => {
+                if self.get(parent_id).is_none() {
+                    return Err(NodeIdError::NodeIdNoLongerValid);
+                }
+
+                let new_id = self.insert_in_slot(node);
+                self.get_mut_unsafe(&new_id).set_parent(Some(parent_id.clone()));
+                self.get_mut_unsafe(parent_id).children_mut().push(new_id.clone());
+
+                Ok(new_id)
+            }
+        }
+    }
+
+    fn insert_in_slot(&mut self, node: Node<T>) -> NodeId {
+        if let Some(index) = self.free_ids.pop() {
+            let generation = self.nodes[index].generation;
+            self.nodes[index].value = Some(node);
+            NodeId::new(index, generation)
+        } else {
+            let index = self.nodes.len();
+            self.nodes.push(Slot {
+                generation: 0,
+                value: Some(node),
+            });
+            NodeId::new(index, 0)
+        }
+    }
+
+    ///
+    /// Removes the `Node` identified by `node_id` from the `Tree` and returns it, handling its
+    /// children according to `behavior`.
+    ///
+    /// The slot `node_id` pointed to is recycled for a future insertion under a new generation,
+    /// so `node_id` (and any clone of it) will no longer resolve via `get`/`get_mut` afterwards.
+    ///
+    pub fn remove_node(&mut self,
+                        node_id: NodeId,
+                        behavior: RemoveBehavior)
+                        -> Result<Node<T>, NodeIdError> {
+        if self.get(&node_id).is_none() {
+            return Err(NodeIdError::NodeIdNoLongerValid);
+        }
+
+        let parent_id = self.get_unsafe(&node_id).parent().cloned();
+        let children_ids = self.get_unsafe(&node_id).children().clone();
+
+        match behavior {
+            DropChildren => {
+                for child_id in &children_ids {
+                    self.remove_node(child_id.clone(), DropChildren)?;
+                }
+            }
+            OrphanChildren => {
+                for child_id in &children_ids {
+                    self.get_mut_unsafe(child_id).set_parent(None);
+                }
+            }
+            LiftChildren => {
+                for child_id in &children_ids {
+                    self.get_mut_unsafe(child_id).set_parent(parent_id.clone());
+                }
+            }
+        }
+
+        if let Some(ref parent_id) = parent_id {
+            let index = self.get_unsafe(parent_id)
+                .children()
+                .iter()
+                .position(|id| id == &node_id)
+                .expect("a Node's parent must list it as a child");
+
+            let parent_children = self.get_mut_unsafe(parent_id).children_mut();
+            if let LiftChildren = behavior {
+                parent_children.splice(index..index + 1, children_ids.clone());
+            } else {
+                parent_children.remove(index);
+            }
+        } else if self.root.as_ref() == Some(&node_id) {
+            self.root = if let LiftChildren = behavior {
+                let mut lifted_ids = children_ids.iter().cloned();
+
+                if let Some(new_root_id) = lifted_ids.next() {
+                    for child_id in lifted_ids {
+                        self.get_mut_unsafe(&child_id).set_parent(Some(new_root_id.clone()));
+                        self.get_mut_unsafe(&new_root_id).children_mut().push(child_id);
+                    }
+
+                    Some(new_root_id)
+                } else {
+                    None
+                }
+            } else {
+                None
+            };
+        }
+
+        let index = node_id.index();
+        let removed = self.nodes[index]
+            .value
+            .take()
+            .expect("NodeId pointed to an empty slot");
+        self.nodes[index].generation += 1;
+        self.free_ids.push(index);
+
+        Ok(removed)
+    }
+
+    ///
+    /// Returns an immutable reference to the `Node` identified by `node_id`, or `None` if
+    /// `node_id` no longer refers to a live `Node` (it was never valid for this `Tree`, or the
+    /// `Node` it pointed to has since been removed).
+    ///
+    pub fn get(&self, node_id: &NodeId) -> Option<&Node<T>> {
+        self.nodes.get(node_id.index()).and_then(|slot| {
+            if slot.generation == node_id.generation() {
+                slot.value.as_ref()
+            } else {
+                None
+            }
+        })
+    }
+
+    ///
+    /// Returns a mutable reference to the `Node` identified by `node_id`, or `None` if `node_id`
+    /// no longer refers to a live `Node`.
+    ///
+    pub fn get_mut(&mut self, node_id: &NodeId) -> Option<&mut Node<T>> {
+        let generation = node_id.generation();
+        self.nodes.get_mut(node_id.index()).and_then(|slot| {
+            if slot.generation == generation {
+                slot.value.as_mut()
+            } else {
+                None
+            }
+        })
+    }
+
+    pub(crate) fn get_unsafe(&self, node_id: &NodeId) -> &Node<T> {
+        self.nodes[node_id.index()]
+            .value
+            .as_ref()
+            .expect("NodeId pointed to an empty slot")
+    }
+
+    pub(crate) fn get_mut_unsafe(&mut self, node_id: &NodeId) -> &mut Node<T> {
+        self.nodes[node_id.index()]
+            .value
+            .as_mut()
+            .expect("NodeId pointed to an empty slot")
+    }
+
+    fn validate(&self, node_id: &NodeId) -> Result<(), NodeIdError> {
+        if self.get(node_id).is_some() {
+            Ok(())
+        } else {
+            Err(NodeIdError::NodeIdNoLongerValid)
+        }
+    }
+
+    ///
+    /// Returns an `Iterator` over the ancestors of the `Node` identified by `node_id`.
+    ///
+    pub fn ancestors(&self, node_id: &NodeId) -> Result<Ancestors<'_, T>, NodeIdError> {
+        self.validate(node_id)?;
+        Ok(Ancestors::new(self, node_id.clone()))
+    }
+
+    ///
+    /// Returns an `Iterator` over the `NodeId`s of the ancestors of the `Node` identified by
+    /// `node_id`.
+    ///
+    pub fn ancestor_ids(&self, node_id: &NodeId) -> Result<AncestorIds<'_, T>, NodeIdError> {
+        self.validate(node_id)?;
+        Ok(AncestorIds::new(self, node_id.clone()))
+    }
+
+    ///
+    /// Returns an `Iterator` over the children of the `Node` identified by `node_id`.
+    ///
+    pub fn children(&self, node_id: &NodeId) -> Result<Children<'_, T>, NodeIdError> {
+        self.validate(node_id)?;
+        Ok(Children::new(self, node_id.clone()))
+    }
+
+    ///
+    /// Returns an `Iterator` over the `NodeId`s of the children of the `Node` identified by
+    /// `node_id`.
+    ///
+    pub fn children_ids(&self, node_id: &NodeId) -> Result<ChildrenIds<'_>, NodeIdError> {
+        self.validate(node_id)?;
+        Ok(ChildrenIds::new(self, node_id.clone()))
+    }
+
+    ///
+    /// Returns an `Iterator` over the sub-tree of the `Node` identified by `node_id`, in
+    /// level-order (breadth-first).
+    ///
+    pub fn traverse_level_order(&self, node_id: &NodeId) -> Result<LevelOrderTraversal<'_, T>, NodeIdError> {
+        self.validate(node_id)?;
+        Ok(LevelOrderTraversal::new(self, node_id.clone()))
+    }
+
+    ///
+    /// Returns an `Iterator` over the `NodeId`s of the sub-tree of the `Node` identified by
+    /// `node_id`, in level-order (breadth-first).
+    ///
+    pub fn traverse_level_order_ids(&self,
+                                     node_id: &NodeId)
+                                     -> Result<LevelOrderTraversalIds<'_, T>, NodeIdError> {
+        self.validate(node_id)?;
+        Ok(LevelOrderTraversalIds::new(self, node_id.clone()))
+    }
+
+    ///
+    /// Returns an `Iterator` over the siblings that come after the `Node` identified by
+    /// `node_id`.
+    ///
+    pub fn next_siblings(&self, node_id: &NodeId) -> Result<NextSiblings<'_, T>, NodeIdError> {
+        self.validate(node_id)?;
+        Ok(NextSiblings::new(self, node_id.clone()))
+    }
+
+    ///
+    /// Returns an `Iterator` over the `NodeId`s of the siblings that come after the `Node`
+    /// identified by `node_id`.
+    ///
+    pub fn next_siblings_ids(&self, node_id: &NodeId) -> Result<NextSiblingsIds<'_>, NodeIdError> {
+        self.validate(node_id)?;
+        Ok(NextSiblingsIds::new(self, node_id.clone()))
+    }
+
+    ///
+    /// Returns an `Iterator` over the siblings that come before the `Node` identified by
+    /// `node_id`.
+    ///
+    pub fn preceding_siblings(&self, node_id: &NodeId) -> Result<PrecedingSiblings<'_, T>, NodeIdError> {
+        self.validate(node_id)?;
+        Ok(PrecedingSiblings::new(self, node_id.clone()))
+    }
+
+    ///
+    /// Returns an `Iterator` over the `NodeId`s of the siblings that come before the `Node`
+    /// identified by `node_id`.
+    ///
+    pub fn preceding_siblings_ids(&self,
+                                   node_id: &NodeId)
+                                   -> Result<PrecedingSiblingsIds<'_>, NodeIdError> {
+        self.validate(node_id)?;
+        Ok(PrecedingSiblingsIds::new(self, node_id.clone()))
+    }
+
+    ///
+    /// Returns an `Iterator` over the `NodeId`s from the root of the `Tree` down to the parent of
+    /// the `Node` identified by `node_id`, in root-first order.  `node_id` itself is not yielded.
+    ///
+    pub fn path_to_root(&self, node_id: &NodeId) -> Result<PathToRoot<'_>, NodeIdError> {
+        self.validate(node_id)?;
+        Ok(PathToRoot::new(self, node_id.clone()))
+    }
+}
+
+impl<T> Default for Tree<T> {
+    fn default() -> Tree<T> {
+        Tree::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use Tree;
+    use Node;
+    use InsertBehavior::*;
+    use RemoveBehavior::*;
+
+    #[test]
+    fn test_get_and_get_mut() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new(0), AsRoot).unwrap();
+
+        assert_eq!(tree.get(&root_id).unwrap().data(), &0);
+
+        *tree.get_mut(&root_id).unwrap().data_mut() = 1;
+
+        assert_eq!(tree.get(&root_id).unwrap().data(), &1);
+    }
+
+    #[test]
+    fn test_stale_node_id_returns_none_after_removal() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new(0), AsRoot).unwrap();
+        let stale_id = tree.insert(Node::new(1), UnderNode(&root_id)).unwrap();
+
+        assert!(tree.get(&stale_id).is_some());
+
+        tree.remove_node(stale_id.clone(), DropChildren).unwrap();
+
+        assert!(tree.get(&stale_id).is_none());
+        assert!(tree.get_mut(&stale_id).is_none());
+        assert_eq!(tree.remove_node(stale_id.clone(), DropChildren),
+                   Err(::NodeIdError::NodeIdNoLongerValid));
+    }
+
+    #[test]
+    fn test_stale_node_id_does_not_alias_reused_slot() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new(0), AsRoot).unwrap();
+        let first_id = tree.insert(Node::new(1), UnderNode(&root_id)).unwrap();
+
+        tree.remove_node(first_id.clone(), DropChildren).unwrap();
+
+        let second_id = tree.insert(Node::new(2), UnderNode(&root_id)).unwrap();
+
+        // The freed slot is reused for the new Node, but the stale NodeId must not resolve to it.
+        assert_ne!(first_id, second_id);
+        assert!(tree.get(&first_id).is_none());
+        assert_eq!(tree.get(&second_id).unwrap().data(), &2);
+    }
+
+    #[test]
+    fn test_orphan_children() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new(0), AsRoot).unwrap();
+        let parent_id = tree.insert(Node::new(1), UnderNode(&root_id)).unwrap();
+        let child_id = tree.insert(Node::new(2), UnderNode(&parent_id)).unwrap();
+
+        tree.remove_node(parent_id.clone(), OrphanChildren).unwrap();
+
+        assert!(tree.get(&parent_id).is_none());
+        assert!(tree.get(&root_id).unwrap().children().is_empty());
+        assert_eq!(tree.get(&child_id).unwrap().parent(), None);
+    }
+
+    #[test]
+    fn test_lift_children_under_non_root_parent() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new(0), AsRoot).unwrap();
+        let parent_id = tree.insert(Node::new(1), UnderNode(&root_id)).unwrap();
+        let child_a = tree.insert(Node::new(2), UnderNode(&parent_id)).unwrap();
+        let child_b = tree.insert(Node::new(3), UnderNode(&parent_id)).unwrap();
+
+        tree.remove_node(parent_id.clone(), LiftChildren).unwrap();
+
+        assert!(tree.get(&parent_id).is_none());
+        assert_eq!(tree.get(&root_id).unwrap().children(), &vec![child_a.clone(), child_b.clone()]);
+        assert_eq!(tree.get(&child_a).unwrap().parent(), Some(&root_id));
+        assert_eq!(tree.get(&child_b).unwrap().parent(), Some(&root_id));
+    }
+
+    #[test]
+    fn test_lift_children_of_root_with_single_child() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new(0), AsRoot).unwrap();
+        let child_id = tree.insert(Node::new(1), UnderNode(&root_id)).unwrap();
+
+        tree.remove_node(root_id.clone(), LiftChildren).unwrap();
+
+        assert!(tree.get(&root_id).is_none());
+        assert_eq!(tree.root_node_id(), Some(&child_id));
+        assert_eq!(tree.get(&child_id).unwrap().parent(), None);
+    }
+
+    #[test]
+    fn test_lift_children_of_root_with_multiple_children() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new(0), AsRoot).unwrap();
+        let child_a = tree.insert(Node::new(1), UnderNode(&root_id)).unwrap();
+        let child_b = tree.insert(Node::new(2), UnderNode(&root_id)).unwrap();
+        let child_c = tree.insert(Node::new(3), UnderNode(&root_id)).unwrap();
+
+        tree.remove_node(root_id.clone(), LiftChildren).unwrap();
+
+        // The first lifted child becomes the new tracked root...
+        assert_eq!(tree.root_node_id(), Some(&child_a));
+        assert_eq!(tree.get(&child_a).unwrap().parent(), None);
+
+        // ...and the rest are attached under it, rather than becoming unreachable.
+        assert_eq!(tree.get(&child_a).unwrap().children(), &vec![child_b.clone(), child_c.clone()]);
+        assert_eq!(tree.get(&child_b).unwrap().parent(), Some(&child_a));
+        assert_eq!(tree.get(&child_c).unwrap().parent(), Some(&child_a));
+
+        assert!(tree.traverse_level_order_ids(&child_a)
+            .unwrap()
+            .collect::<Vec<_>>()
+            .contains(&child_b));
+    }
+}
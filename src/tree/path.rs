@@ -0,0 +1,110 @@
+use Tree;
+use NodeId;
+
+impl<T> Tree<T> {
+    ///
+    /// Looks up a descendant of `start_id` by following a sequence of path segments.
+    ///
+    /// Each segment of `path` is resolved against the current `Node`'s children in turn, using
+    /// `resolve` to test whether a given child matches that segment.  Returns a reference to the
+    /// `NodeId` of the `Node` reached once every segment has matched, or `None` as soon as a
+    /// segment fails to match any child.  Also returns `None` if `start_id` no longer refers to a
+    /// live `Node`, rather than panicking or resolving to whatever now occupies its slot.
+    ///
+    /// This is meant for callers that address `Node`s by a sequence of child selections (as when
+    /// modeling a filesystem or a nested document) rather than by an opaque `NodeId`.
+    ///
+    pub fn get_by_path<'a, P, F>(&'a self,
+                                  start_id: &'a NodeId,
+                                  path: &[P],
+                                  mut resolve: F)
+                                  -> Option<&'a NodeId>
+        where F: FnMut(&P, &NodeId) -> bool
+    {
+        self.get(start_id)?;
+
+        let mut current_id = start_id;
+
+        for segment in path {
+            let children = self.get_unsafe(current_id).children();
+
+            match children.iter().find(|child_id| resolve(segment, child_id)) {
+                Some(child_id) => current_id = child_id,
+                None => return None,
+            }
+        }
+
+        Some(current_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use Tree;
+    use Node;
+    use InsertBehavior::*;
+    use RemoveBehavior::*;
+
+    #[test]
+    fn test_get_by_path() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new("root"), AsRoot).unwrap();
+        let child_a = tree.insert(Node::new("a"), UnderNode(&root_id)).unwrap();
+        let child_b = tree.insert(Node::new("b"), UnderNode(&root_id)).unwrap();
+        let grandchild = tree.insert(Node::new("c"), UnderNode(&child_a)).unwrap();
+
+        let resolve = |segment: &&str, child_id: &::NodeId| tree.get(child_id).unwrap().data() ==
+                      segment;
+
+        let found = tree.get_by_path(&root_id, &["a", "c"], resolve).unwrap();
+        assert_eq!(found, &grandchild);
+
+        let found = tree.get_by_path(&root_id, &["b"], resolve).unwrap();
+        assert_eq!(found, &child_b);
+    }
+
+    #[test]
+    fn test_get_by_path_no_match() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new("root"), AsRoot).unwrap();
+        tree.insert(Node::new("a"), UnderNode(&root_id)).unwrap();
+
+        let resolve = |segment: &&str, child_id: &::NodeId| tree.get(child_id).unwrap().data() ==
+                      segment;
+
+        assert!(tree.get_by_path(&root_id, &["z"], resolve).is_none());
+        assert!(tree.get_by_path(&root_id, &["a", "z"], resolve).is_none());
+    }
+
+    #[test]
+    fn test_get_by_path_stale_start_id_returns_none() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new("root"), AsRoot).unwrap();
+        let stale_id = tree.insert(Node::new("a"), UnderNode(&root_id)).unwrap();
+
+        tree.remove_node(stale_id.clone(), DropChildren).unwrap();
+
+        let resolve = |segment: &&str, child_id: &::NodeId| tree.get(child_id).unwrap().data() ==
+                      segment;
+
+        assert!(tree.get_by_path(&stale_id, &["z"], resolve).is_none());
+    }
+
+    #[test]
+    fn test_get_by_path_empty_path_returns_start_id() {
+        let mut tree = Tree::new();
+
+        let root_id = tree.insert(Node::new("root"), AsRoot).unwrap();
+
+        let resolve = |segment: &&str, child_id: &::NodeId| tree.get(child_id).unwrap().data() ==
+                      segment;
+
+        let empty: [&str; 0] = [];
+        let found = tree.get_by_path(&root_id, &empty, resolve).unwrap();
+        assert_eq!(found, &root_id);
+    }
+}